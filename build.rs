@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Precomputes the rook and bishop magic numbers used by `src/magic/mod.rs`.
+//!
+//! Build scripts run before the crate itself is compiled, so this cannot
+//! import the crate's own types -- it duplicates the bare minimum of
+//! `src/rng.rs` and the ray-walking helpers from `src/magic/mod.rs` using raw
+//! `u64`s instead. The search uses a fixed seed, so every build produces the
+//! same magic numbers.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Seed used for the magic-number search, kept fixed so the tables (and thus
+/// the indices squares hash to) are reproducible across runs.
+const SEED: u64 = 0x526F_6F6B_6965;
+
+const ROOK_DIRS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// A splitmix64 pseudo-random number generator, duplicated from `src/rng.rs`.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    fn next_sparse_u64(&mut self) -> u64 {
+        self.next_u64() & self.next_u64() & self.next_u64()
+    }
+}
+
+/// Walks every ray in `dirs` from `sq`, stopping (inclusive) at the first
+/// blocker set in `occ`, and returns the resulting attack set as a raw mask.
+fn ray_attacks(sq: u8, occ: u64, dirs: [(i8, i8); 4]) -> u64 {
+    let rank = (sq / 8) as i8;
+    let file = (sq % 8) as i8;
+    let mut attacked = 0u64;
+
+    for (dr, df) in dirs {
+        let mut r = rank + dr;
+        let mut f = file + df;
+
+        while (0..8).contains(&r) && (0..8).contains(&f) {
+            let bit = 1u64 << (r * 8 + f);
+            attacked |= bit;
+
+            if occ & bit != 0 {
+                break;
+            }
+
+            r += dr;
+            f += df;
+        }
+    }
+
+    attacked
+}
+
+/// Computes the relevant-occupancy mask for `sq`, mirroring
+/// `magic::relevant_mask`: every square along the rays in `dirs`, excluding
+/// the last square of each ray (the board edge), since a blocker there never
+/// changes whether it can be moved onto.
+fn relevant_mask(sq: u8, dirs: [(i8, i8); 4]) -> u64 {
+    let rank = (sq / 8) as i8;
+    let file = (sq % 8) as i8;
+    let mut relevant = 0u64;
+
+    for (dr, df) in dirs {
+        let mut r = rank + dr;
+        let mut f = file + df;
+
+        while (0..8).contains(&r) && (0..8).contains(&f) && (0..8).contains(&(r + dr)) && (0..8).contains(&(f + df)) {
+            relevant |= 1u64 << (r * 8 + f);
+            r += dr;
+            f += df;
+        }
+    }
+
+    relevant
+}
+
+/// Enumerates every blocker subset of `mask` via the carry-rippler trick.
+fn subsets(mask: u64) -> Vec<u64> {
+    let mut subsets = Vec::with_capacity(1 << mask.count_ones());
+    let mut sub = 0u64;
+
+    loop {
+        subsets.push(sub);
+        sub = sub.wrapping_sub(mask) & mask;
+
+        if sub == 0 {
+            break;
+        }
+    }
+
+    subsets
+}
+
+/// Searches for a magic number that hashes every blocker subset of `mask` to
+/// a collision-free (or attack-consistent) index.
+fn find_magic(sq: u8, mask: u64, dirs: [(i8, i8); 4], rng: &mut Rng) -> u64 {
+    let bits = mask.count_ones();
+    let shift = 64 - bits;
+    let occupancies = subsets(mask);
+    let attacks: Vec<u64> = occupancies.iter().map(|occ| ray_attacks(sq, *occ, dirs)).collect();
+
+    loop {
+        let magic = rng.next_sparse_u64();
+        let mut table: Vec<Option<u64>> = vec![None; 1 << bits];
+        let mut collision = false;
+
+        for (occ, attack) in occupancies.iter().zip(attacks.iter()) {
+            let index = (occ.wrapping_mul(magic) >> shift) as usize;
+
+            match table[index] {
+                None => table[index] = Some(*attack),
+                Some(existing) if existing == *attack => {}
+                Some(_) => {
+                    collision = true;
+                    break;
+                }
+            }
+        }
+
+        if !collision {
+            return magic;
+        }
+    }
+}
+
+/// Searches for a magic number for every square, using a freshly-seeded `Rng`
+/// so each piece's table is independent of the other's search order.
+fn find_magics(dirs: [(i8, i8); 4]) -> [u64; 64] {
+    let mut rng = Rng::new(SEED);
+
+    std::array::from_fn(|sq| find_magic(sq as u8, relevant_mask(sq as u8, dirs), dirs, &mut rng))
+}
+
+fn emit_table(out: &mut String, name: &str, magics: [u64; 64]) {
+    writeln!(out, "pub(crate) const {name}: [u64; 64] = [").unwrap();
+
+    for magic in magics {
+        writeln!(out, "    0x{magic:016X},").unwrap();
+    }
+
+    writeln!(out, "];").unwrap();
+}
+
+fn main() {
+    let mut out = String::new();
+    emit_table(&mut out, "ROOK_MAGICS", find_magics(ROOK_DIRS));
+    emit_table(&mut out, "BISHOP_MAGICS", find_magics(BISHOP_DIRS));
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("magics.rs"), out).unwrap();
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Precomputed attack tables for the non-sliding (leaper) pieces.
+//!
+//! Knights, kings, and pawns attack a fixed pattern of squares regardless of
+//! board occupancy, so each square's (and, for pawns, each color's) attack
+//! set can be precomputed once from a single-bit board via `Bitboard::shift`.
+
+use std::sync::OnceLock;
+
+use super::{Bitboard, Direction, EMPTY};
+use crate::loc::Loc;
+
+fn combine(board: Bitboard, shifts: &[&[Direction]]) -> Bitboard {
+    shifts.iter().fold(EMPTY, |acc, path| {
+        acc | path.iter().fold(board, |b, dir| b.shift(*dir))
+    })
+}
+
+fn knight_attacks_from(board: Bitboard) -> Bitboard {
+    use Direction::*;
+
+    combine(
+        board,
+        &[
+            &[North, North, East],
+            &[North, North, West],
+            &[South, South, East],
+            &[South, South, West],
+            &[East, East, North],
+            &[East, East, South],
+            &[West, West, North],
+            &[West, West, South],
+        ],
+    )
+}
+
+fn king_attacks_from(board: Bitboard) -> Bitboard {
+    use Direction::*;
+
+    combine(
+        board,
+        &[
+            &[North],
+            &[South],
+            &[East],
+            &[West],
+            &[NorthEast],
+            &[NorthWest],
+            &[SouthEast],
+            &[SouthWest],
+        ],
+    )
+}
+
+fn pawn_attacks_from(board: Bitboard, white: bool) -> Bitboard {
+    use Direction::*;
+
+    if white {
+        combine(board, &[&[NorthEast], &[NorthWest]])
+    } else {
+        combine(board, &[&[SouthEast], &[SouthWest]])
+    }
+}
+
+static KNIGHT_ATTACKS: OnceLock<[Bitboard; 64]> = OnceLock::new();
+static KING_ATTACKS: OnceLock<[Bitboard; 64]> = OnceLock::new();
+static PAWN_ATTACKS: OnceLock<[[Bitboard; 64]; 2]> = OnceLock::new();
+
+fn table_of(f: impl Fn(Bitboard) -> Bitboard) -> [Bitboard; 64] {
+    std::array::from_fn(|sq| {
+        let loc = Loc::from_index(sq as u8).expect("sq is in 0..64");
+
+        f(Bitboard::from_single(&loc))
+    })
+}
+
+/// Returns the squares a knight standing on `loc` attacks.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::knight_attacks;
+/// use rookie::loc::Loc;
+///
+/// let attacks = knight_attacks(&Loc::new(3, 3).unwrap());
+///
+/// assert_eq!(attacks.count(), 8);
+/// assert!(attacks.at(&Loc::new(5, 4).unwrap()));
+/// assert!(attacks.at(&Loc::new(1, 2).unwrap()));
+///
+/// // A knight in the corner only has two squares to attack.
+/// let corner = knight_attacks(&Loc::new(0, 0).unwrap());
+///
+/// assert_eq!(corner.count(), 2);
+/// assert!(corner.at(&Loc::new(2, 1).unwrap()));
+/// assert!(corner.at(&Loc::new(1, 2).unwrap()));
+/// ```
+pub fn knight_attacks(loc: &Loc) -> Bitboard {
+    let table = KNIGHT_ATTACKS.get_or_init(|| table_of(knight_attacks_from));
+
+    table[loc.index() as usize]
+}
+
+/// Returns the squares a king standing on `loc` attacks.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::king_attacks;
+/// use rookie::loc::Loc;
+///
+/// let attacks = king_attacks(&Loc::new(3, 3).unwrap());
+///
+/// assert_eq!(attacks.count(), 8);
+/// assert!(attacks.at(&Loc::new(4, 4).unwrap()));
+///
+/// // A king in the corner only has three squares to attack.
+/// let corner = king_attacks(&Loc::new(0, 0).unwrap());
+///
+/// assert_eq!(corner.count(), 3);
+/// assert!(corner.at(&Loc::new(1, 1).unwrap()));
+/// ```
+pub fn king_attacks(loc: &Loc) -> Bitboard {
+    let table = KING_ATTACKS.get_or_init(|| table_of(king_attacks_from));
+
+    table[loc.index() as usize]
+}
+
+/// Returns the squares a pawn of color `white` standing on `loc` attacks.
+///
+/// This only covers diagonal capture squares, not the pawn's forward pushes.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::pawn_attacks;
+/// use rookie::loc::Loc;
+///
+/// let d4 = Loc::new(3, 3).unwrap();
+///
+/// let white = pawn_attacks(&d4, true);
+/// assert_eq!(white.count(), 2);
+/// assert!(white.at(&Loc::new(4, 2).unwrap()));
+/// assert!(white.at(&Loc::new(4, 4).unwrap()));
+///
+/// let black = pawn_attacks(&d4, false);
+/// assert_eq!(black.count(), 2);
+/// assert!(black.at(&Loc::new(2, 2).unwrap()));
+/// assert!(black.at(&Loc::new(2, 4).unwrap()));
+/// ```
+pub fn pawn_attacks(loc: &Loc, white: bool) -> Bitboard {
+    let tables = PAWN_ATTACKS.get_or_init(|| {
+        [
+            table_of(|b| pawn_attacks_from(b, true)),
+            table_of(|b| pawn_attacks_from(b, false)),
+        ]
+    });
+
+    let color = if white { 0 } else { 1 };
+
+    tables[color][loc.index() as usize]
+}
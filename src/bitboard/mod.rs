@@ -3,12 +3,71 @@
 
 use crate::loc::Loc;
 
+mod attacks;
+mod result;
+
+pub use attacks::{king_attacks, knight_attacks, pawn_attacks};
+pub use self::result::BitboardError;
+
+use self::result::Result;
+
+/// A compass direction a `Bitboard` can be shifted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+const fn rank_mask(rank: u8) -> Bitboard {
+    Bitboard(0xFFu64 << (8 * rank as u32))
+}
+
+const fn file_mask(file: u8) -> Bitboard {
+    Bitboard(0x0101_0101_0101_0101u64 << file)
+}
+
+/// Bitboards of each of the eight ranks, indexed `0` (rank 1) to `7` (rank 8).
+pub const RANKS: [Bitboard; 8] = [
+    rank_mask(0),
+    rank_mask(1),
+    rank_mask(2),
+    rank_mask(3),
+    rank_mask(4),
+    rank_mask(5),
+    rank_mask(6),
+    rank_mask(7),
+];
+
+/// Bitboards of each of the eight files, indexed `0` (file A) to `7` (file H).
+pub const FILES: [Bitboard; 8] = [
+    file_mask(0),
+    file_mask(1),
+    file_mask(2),
+    file_mask(3),
+    file_mask(4),
+    file_mask(5),
+    file_mask(6),
+    file_mask(7),
+];
+
+/// A bitboard with no squares set.
+pub const EMPTY: Bitboard = Bitboard(0);
+
+/// A bitboard with every square set.
+pub const ALL: Bitboard = Bitboard(u64::MAX);
+
 /// A single bitboard.
 ///
 /// Bitboards are a basic building block of bitboard board representation. Under
 /// such representation a board is a collection of bitmasks. If bit n is set in
 /// a bitboard, that represents a square with index n containing a piece.
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Bitboard(u64);
 
 /// An iterator over the set bits of a `Bitboard`.
@@ -83,6 +142,73 @@ impl std::ops::BitXorAssign for Bitboard {
     }
 }
 
+/// Implements the bitwise-NOT operator for `Bitboard`.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::{Bitboard, EMPTY};
+/// use rookie::loc::Loc;
+///
+/// let a1 = Bitboard::from_single(&Loc::new(0, 0).unwrap());
+///
+/// assert!(!(!a1).at(&Loc::new(0, 0).unwrap()));
+/// assert!((!EMPTY).at(&Loc::new(3, 3).unwrap()));
+/// ```
+impl std::ops::Not for Bitboard {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Bitboard(!self.0)
+    }
+}
+
+/// Implements set difference (squares in `self` but not in `rhs`) for `Bitboard`.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::Bitboard;
+/// use rookie::loc::Loc;
+///
+/// let a1 = Loc::new(0, 0).unwrap();
+/// let b1 = Loc::new(0, 1).unwrap();
+/// let both = Bitboard::new(&[a1, b1]);
+/// let just_a1 = both - Bitboard::from_single(&b1);
+///
+/// assert!(just_a1.at(&a1));
+/// assert!(!just_a1.at(&b1));
+/// ```
+impl std::ops::Sub for Bitboard {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Bitboard(self.0 & !rhs.0)
+    }
+}
+
+/// Collects an iterator of `Loc`s into a `Bitboard` with those squares set.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::Bitboard;
+/// use rookie::loc::Loc;
+///
+/// let a1 = Loc::new(0, 0).unwrap();
+/// let h8 = Loc::new(7, 7).unwrap();
+/// let board: Bitboard = [a1, h8].into_iter().collect();
+///
+/// assert!(board.at(&a1));
+/// assert!(board.at(&h8));
+/// assert_eq!(board.count(), 2);
+/// ```
+impl FromIterator<Loc> for Bitboard {
+    fn from_iter<T: IntoIterator<Item = Loc>>(iter: T) -> Self {
+        iter.into_iter().fold(EMPTY, |acc, loc| acc | Bitboard::from_single(&loc))
+    }
+}
+
 impl Bitboard {
     /// Creates a new bitboard.
     ///
@@ -100,10 +226,10 @@ impl Bitboard {
     ///
     /// let bitboard = Bitboard::new(&indices);
     ///
-    /// assert!(bitboard.at(Loc::from_index(0).unwrap()));
-    /// assert!(bitboard.at(Loc::from_index(5).unwrap()));
-    /// assert!(bitboard.at(Loc::from_index(13).unwrap()));
-    /// assert!(bitboard.at(Loc::from_index(22).unwrap()));
+    /// assert!(bitboard.at(&Loc::from_index(0).unwrap()));
+    /// assert!(bitboard.at(&Loc::from_index(5).unwrap()));
+    /// assert!(bitboard.at(&Loc::from_index(13).unwrap()));
+    /// assert!(bitboard.at(&Loc::from_index(22).unwrap()));
     /// ```
     ///
     /// # Arguments
@@ -121,7 +247,7 @@ impl Bitboard {
     }
 
     /// Initializes a bitboard containing a single piece.
-    pub fn from_single(loc: Loc) -> Self {
+    pub fn from_single(loc: &Loc) -> Self {
         Self(1 << loc.index())
     }
 
@@ -130,6 +256,15 @@ impl Bitboard {
         Self(raw)
     }
 
+    /// Exposes the raw 64-bit representation.
+    ///
+    /// This is only needed by crate-internal code (e.g. magic-bitboard index
+    /// hashing) that must do its own bit arithmetic; ordinary callers should
+    /// stick to the higher-level `Bitboard` API.
+    pub(crate) fn raw(&self) -> u64 {
+        self.0
+    }
+
     /// Check if square is set.
     ///
     /// # Examples
@@ -141,12 +276,12 @@ impl Bitboard {
     /// let board = Bitboard::from_u64(0b0000_1001);
     ///
     /// // These are set
-    /// assert!(board.at(Loc::from_index(0).unwrap()));
-    /// assert!(board.at(Loc::from_index(3).unwrap()));
+    /// assert!(board.at(&Loc::from_index(0).unwrap()));
+    /// assert!(board.at(&Loc::from_index(3).unwrap()));
     ///
     /// // These are some of the squares that are not set
-    /// assert!(!board.at(Loc::from_index(1).unwrap()));
-    /// assert!(!board.at(Loc::from_index(2).unwrap()));
+    /// assert!(!board.at(&Loc::from_index(1).unwrap()));
+    /// assert!(!board.at(&Loc::from_index(2).unwrap()));
     /// ```
     ///
     /// # Arguments
@@ -158,7 +293,7 @@ impl Bitboard {
     /// State of the polled square.
     /// * `true`: when the square contains a piece.
     /// * `false`: when the square does not contain a piece.
-    pub fn at(&self, loc: Loc) -> bool {
+    pub fn at(&self, loc: &Loc) -> bool {
         (self.0 & (1 << loc.index())) != 0
     }
 
@@ -183,4 +318,109 @@ impl Bitboard {
     pub fn iter(&self) -> BitboardIter {
         BitboardIter(self.0)
     }
+
+    /// Counts the number of set squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::bitboard::{Bitboard, EMPTY};
+    ///
+    /// assert_eq!(Bitboard::from_u64(0b0010_1001).count(), 3);
+    /// assert_eq!(EMPTY.count(), 0);
+    /// ```
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Checks whether no square is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::bitboard::{Bitboard, EMPTY};
+    /// use rookie::loc::Loc;
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// assert!(!Bitboard::from_single(&Loc::new(0, 0).unwrap()).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks whether more than one square is set.
+    ///
+    /// This is cheaper than `count() > 1`: clearing the lowest set bit and
+    /// checking for a remainder needs no loop, unlike a full popcount.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::bitboard::Bitboard;
+    /// use rookie::loc::Loc;
+    ///
+    /// assert!(!Bitboard::from_single(&Loc::new(0, 0).unwrap()).has_more_than_one());
+    /// assert!(Bitboard::from_u64(0b11).has_more_than_one());
+    /// ```
+    pub fn has_more_than_one(&self) -> bool {
+        self.0 & self.0.wrapping_sub(1) != 0
+    }
+
+    /// Extracts the sole set square.
+    ///
+    /// # Returns
+    ///
+    /// The `Loc` of the single set square, or a `BitboardError` if zero or
+    /// more than one square is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::bitboard::{Bitboard, EMPTY};
+    /// use rookie::loc::Loc;
+    ///
+    /// let e5 = Loc::new(4, 4).unwrap();
+    ///
+    /// assert_eq!(Bitboard::from_single(&e5).try_into_loc().unwrap(), e5);
+    /// assert!(EMPTY.try_into_loc().is_err());
+    /// assert!(Bitboard::from_u64(0b11).try_into_loc().is_err());
+    /// ```
+    pub fn try_into_loc(&self) -> Result<Loc> {
+        if self.has_more_than_one() || self.is_empty() {
+            return Err(BitboardError::NotASingleSquare(self.count()));
+        }
+
+        Ok(Loc::from_index(self.0.trailing_zeros() as u8).expect("a single bit is set"))
+    }
+
+    /// Shifts every set square one step in `dir`, discarding squares that
+    /// would fall off the board.
+    ///
+    /// Horizontal and diagonal shifts additionally strip squares that would
+    /// otherwise wrap around from file H to file A (or vice versa).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::bitboard::{Bitboard, Direction};
+    /// use rookie::loc::Loc;
+    ///
+    /// let h4 = Bitboard::from_single(&Loc::new(3, 7).unwrap());
+    ///
+    /// assert!(h4.shift(Direction::East).iter().next().is_none());
+    /// ```
+    pub fn shift(&self, dir: Direction) -> Bitboard {
+        use Direction::*;
+
+        match dir {
+            North => Bitboard(self.0 << 8),
+            South => Bitboard(self.0 >> 8),
+            East => Bitboard((self.0 << 1) & !FILES[0].raw()),
+            West => Bitboard((self.0 >> 1) & !FILES[7].raw()),
+            NorthEast => Bitboard((self.0 << 9) & !FILES[0].raw()),
+            NorthWest => Bitboard((self.0 << 7) & !FILES[7].raw()),
+            SouthEast => Bitboard((self.0 >> 7) & !FILES[0].raw()),
+            SouthWest => Bitboard((self.0 >> 9) & !FILES[7].raw()),
+        }
+    }
 }
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, BitboardError>;
+
+#[derive(Error, Debug)]
+pub enum BitboardError {
+    #[error("expected exactly one set square, found {0}")]
+    NotASingleSquare(u32),
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! FEN (Forsyth-Edwards Notation) piece-placement import and export.
+
+use super::result::{BoardError, Result};
+use super::{Board, Piece, PieceKind};
+use crate::loc::Loc;
+
+impl Board {
+    /// Parses the piece-placement field of a FEN string into a `Board`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fen`: The piece-placement field, ranks separated by `/`, ordered
+    ///   from rank 8 down to rank 1 as in a full FEN record.
+    ///
+    /// # Returns
+    ///
+    /// The parsed board, or a `BoardError::InvalidFen` if `fen` does not
+    /// have exactly 8 ranks, a rank does not sum to 8 files, a character is
+    /// not a recognized piece letter or digit, two digits appear back to
+    /// back, or a digit is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::Board;
+    ///
+    /// let board = Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR").unwrap();
+    ///
+    /// assert_eq!(board.to_fen_placement(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR");
+    ///
+    /// // Consecutive digits and a leading zero both sum to 8 files, but
+    /// // neither is valid FEN syntax.
+    /// assert!(Board::from_fen("44/8/8/8/8/8/8/8").is_err());
+    /// assert!(Board::from_fen("0pppppp0/8/8/8/8/8/8/8").is_err());
+    /// ```
+    pub fn from_fen(fen: &str) -> Result<Board> {
+        let ranks: Vec<&str> = fen.split('/').collect();
+
+        if ranks.len() != 8 {
+            return Err(BoardError::invalid_fen("placement must have 8 ranks"));
+        }
+
+        let mut builder = Board::builder();
+
+        for (row, rank_str) in ranks.iter().enumerate() {
+            let rank = 7 - row as u8;
+            let mut file = 0u8;
+            let mut prev_was_digit = false;
+
+            for c in rank_str.chars() {
+                if let Some(skip) = c.to_digit(10) {
+                    if skip == 0 {
+                        return Err(BoardError::invalid_fen("digit '0' is not a valid empty-square count"));
+                    }
+
+                    if prev_was_digit {
+                        return Err(BoardError::invalid_fen("consecutive digits are not allowed"));
+                    }
+
+                    file += skip as u8;
+                    prev_was_digit = true;
+                    continue;
+                }
+
+                let (kind, white) = piece_from_char(c)
+                    .ok_or_else(|| BoardError::invalid_fen(&format!("unknown piece '{c}'")))?;
+                let loc = Loc::new(rank, file)
+                    .ok_or_else(|| BoardError::invalid_fen("rank does not sum to 8 files"))?;
+
+                builder = builder.add_piece(&Piece { kind, white }, &loc);
+                file += 1;
+                prev_was_digit = false;
+            }
+
+            if file != 8 {
+                return Err(BoardError::invalid_fen("rank does not sum to 8 files"));
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Serializes the piece-placement field of `self` to FEN.
+    ///
+    /// This is the inverse of `from_fen`: ranks are emitted from rank 8 down
+    /// to rank 1, and runs of empty squares are collapsed into digits.
+    pub fn to_fen_placement(&self) -> String {
+        let mut fen = String::new();
+
+        for rank in (0..8).rev() {
+            let mut empty = 0u8;
+
+            for file in 0..8 {
+                let loc = Loc::new(rank, file).expect("rank and file are in 0..8");
+                let piece = self
+                    .at(&loc)
+                    .expect("board built via BoardBuilder cannot be corrupt");
+
+                match piece {
+                    None => empty += 1,
+                    Some(piece) => {
+                        if empty > 0 {
+                            fen.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+
+                        fen.push(piece_to_char(&piece));
+                    }
+                }
+            }
+
+            if empty > 0 {
+                fen.push_str(&empty.to_string());
+            }
+
+            if rank > 0 {
+                fen.push('/');
+            }
+        }
+
+        fen
+    }
+}
+
+fn piece_from_char(c: char) -> Option<(PieceKind, bool)> {
+    use PieceKind::*;
+
+    let kind = match c.to_ascii_uppercase() {
+        'P' => Pawn,
+        'N' => Knight,
+        'B' => Bishop,
+        'R' => Rook,
+        'Q' => Queen,
+        'K' => King,
+        _ => return None,
+    };
+
+    Some((kind, c.is_ascii_uppercase()))
+}
+
+fn piece_to_char(piece: &Piece) -> char {
+    use PieceKind::*;
+
+    let c = match piece.kind {
+        Pawn => 'p',
+        Knight => 'n',
+        Bishop => 'b',
+        Rook => 'r',
+        Queen => 'q',
+        King => 'k',
+    };
+
+    if piece.white {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
@@ -1,11 +1,16 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // SPDX-FileCopyrightText: Duszku
 
-mod result;
+mod fen;
+mod mutate;
+pub(crate) mod result;
+mod zobrist;
 
-use crate::bitboard::Bitboard;
+use crate::bitboard::{self, Bitboard};
 use crate::loc::Loc;
-use self::result::{Result, BoardError};
+use crate::magic;
+use self::result::Result;
+pub use self::result::BoardError;
 
 /// All the different types of chess pieces.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,7 +35,7 @@ pub struct Piece {
 /// This strucure encapsulates positioning of every piece on the chessboard, but
 /// does not keep all information regarding state of the game, such as active
 /// colour or potential en passant.
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Board {
     black: Bitboard,
     white: Bitboard,
@@ -182,4 +187,239 @@ impl Board {
     pub fn pieces(&self, pattern: &Piece) -> Bitboard {
         self[pattern.kind] & self[pattern.white]
     }
+
+    /// Returns every piece of color `by_white` that attacks `loc`.
+    fn attackers_of(&self, loc: &Loc, by_white: bool) -> Bitboard {
+        use PieceKind::*;
+
+        let occ = self[true] | self[false];
+        let rook_sliders = self.pieces(&Piece { kind: Rook, white: by_white })
+            | self.pieces(&Piece { kind: Queen, white: by_white });
+        let bishop_sliders = self.pieces(&Piece { kind: Bishop, white: by_white })
+            | self.pieces(&Piece { kind: Queen, white: by_white });
+
+        bitboard::knight_attacks(loc) & self.pieces(&Piece { kind: Knight, white: by_white })
+            | bitboard::king_attacks(loc) & self.pieces(&Piece { kind: King, white: by_white })
+            | bitboard::pawn_attacks(loc, !by_white) & self.pieces(&Piece { kind: Pawn, white: by_white })
+            | magic::rook_attacks(loc, occ) & rook_sliders
+            | magic::bishop_attacks(loc, occ) & bishop_sliders
+    }
+
+    /// Returns the enemy pieces currently giving check to `white`'s king.
+    ///
+    /// If `white` has no king on the board, this returns an empty bitboard
+    /// rather than erroring; use `is_valid` to enforce that a king exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::{Board, Piece, PieceKind};
+    /// use rookie::loc::Loc;
+    ///
+    /// let king = Piece { kind: PieceKind::King, white: true };
+    /// let rook = Piece { kind: PieceKind::Rook, white: false };
+    /// let a1 = Loc::new(0, 0).unwrap();
+    ///
+    /// let board = Board::builder()
+    ///     .add_piece(&king, &Loc::new(0, 4).unwrap())
+    ///     .add_piece(&rook, &a1)
+    ///     .build();
+    ///
+    /// let checkers = board.checkers(true);
+    ///
+    /// assert_eq!(checkers.count(), 1);
+    /// assert!(checkers.at(&a1));
+    ///
+    /// // A board with no king of that color is never in check.
+    /// assert!(Board::default().checkers(true).is_empty());
+    /// ```
+    pub fn checkers(&self, white: bool) -> Bitboard {
+        match self.pieces(&Piece { kind: PieceKind::King, white }).iter().next() {
+            Some(king) => self.attackers_of(&king, !white),
+            None => bitboard::EMPTY,
+        }
+    }
+
+    /// Returns whether `white`'s king is currently in check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::{Board, Piece, PieceKind};
+    /// use rookie::loc::Loc;
+    ///
+    /// let king = Piece { kind: PieceKind::King, white: true };
+    /// let rook = Piece { kind: PieceKind::Rook, white: false };
+    ///
+    /// let board = Board::builder()
+    ///     .add_piece(&king, &Loc::new(0, 4).unwrap())
+    ///     .add_piece(&rook, &Loc::new(0, 0).unwrap())
+    ///     .build();
+    ///
+    /// assert!(board.in_check(true));
+    /// assert!(!board.in_check(false));
+    /// ```
+    pub fn in_check(&self, white: bool) -> bool {
+        !self.checkers(white).is_empty()
+    }
+
+    /// Returns whether `loc` is attacked by any piece of color `by_white`.
+    ///
+    /// Used by castling legality (a king may not pass through or land on an
+    /// attacked square) independently of whether a king actually stands on
+    /// `loc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::{Board, Piece, PieceKind};
+    /// use rookie::loc::Loc;
+    ///
+    /// let rook = Piece { kind: PieceKind::Rook, white: false };
+    /// let board = Board::builder().add_piece(&rook, &Loc::new(0, 0).unwrap()).build();
+    ///
+    /// assert!(board.square_attacked_by(&Loc::new(0, 4).unwrap(), false));
+    /// assert!(!board.square_attacked_by(&Loc::new(4, 4).unwrap(), false));
+    /// ```
+    pub fn square_attacked_by(&self, loc: &Loc, by_white: bool) -> bool {
+        !self.attackers_of(loc, by_white).is_empty()
+    }
+
+    /// Checks the structural and positional soundness of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `white_to_move`: The side whose turn it currently is, needed to
+    ///   check that the side that just moved didn't leave its own king in
+    ///   check.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the board has exactly one king per color, the color
+    /// bitboards are disjoint and together equal the union of all
+    /// piece-kind bitboards, no square is claimed by more than one piece
+    /// kind, and the side not to move is not in check. Otherwise a
+    /// `BoardError::BoardCorruption` describing the first violation found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::{Board, Piece, PieceKind};
+    /// use rookie::loc::Loc;
+    ///
+    /// let white_king = Piece { kind: PieceKind::King, white: true };
+    /// let black_king = Piece { kind: PieceKind::King, white: false };
+    ///
+    /// let board = Board::builder()
+    ///     .add_piece(&white_king, &Loc::new(0, 4).unwrap())
+    ///     .add_piece(&black_king, &Loc::new(7, 4).unwrap())
+    ///     .build();
+    ///
+    /// assert!(board.is_valid(true).is_ok());
+    ///
+    /// // A board missing a king violates the "exactly one king" check.
+    /// assert!(Board::default().is_valid(true).is_err());
+    ///
+    /// // White to move, but it's black's king (the side that just moved)
+    /// // sitting in check from the rook on a8 — an illegal position.
+    /// let white_rook = Piece { kind: PieceKind::Rook, white: true };
+    /// let board = Board::builder()
+    ///     .add_piece(&white_king, &Loc::new(0, 4).unwrap())
+    ///     .add_piece(&black_king, &Loc::new(7, 4).unwrap())
+    ///     .add_piece(&white_rook, &Loc::new(7, 0).unwrap())
+    ///     .build();
+    ///
+    /// assert!(board.is_valid(true).is_err());
+    /// ```
+    pub fn is_valid(&self, white_to_move: bool) -> Result<()> {
+        use PieceKind::*;
+
+        for white in [true, false] {
+            let kings = self.pieces(&Piece { kind: King, white }).iter().count();
+
+            if kings != 1 {
+                return Err(BoardError::board_corruption(&format!(
+                    "expected exactly one {} king, found {kings}",
+                    if white { "white" } else { "black" },
+                )));
+            }
+        }
+
+        if (self[true] & self[false]) != bitboard::EMPTY {
+            return Err(BoardError::board_corruption(
+                "white and black piece sets overlap",
+            ));
+        }
+
+        let mut union = bitboard::EMPTY;
+
+        for kind in [Pawn, Knight, Bishop, Rook, Queen, King] {
+            if (union & self[kind]) != bitboard::EMPTY {
+                return Err(BoardError::board_corruption(
+                    "a square is claimed by more than one piece kind",
+                ));
+            }
+
+            union |= self[kind];
+        }
+
+        if union != (self[true] | self[false]) {
+            return Err(BoardError::board_corruption(
+                "color bitboards do not match the union of piece-kind bitboards",
+            ));
+        }
+
+        if self.in_check(!white_to_move) {
+            return Err(BoardError::board_corruption(
+                "side not to move is in check",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// `BoardBuilder` always sets a piece's color and kind bits together, so the
+// union-mismatch check below can never be triggered through the public API.
+// These tests set up each corruption by poking at the private fields
+// directly, which is why they live here instead of as doctests.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kings_only() -> Board {
+        Board::builder()
+            .add_piece(&Piece { kind: PieceKind::King, white: true }, &Loc::new(0, 4).unwrap())
+            .add_piece(&Piece { kind: PieceKind::King, white: false }, &Loc::new(7, 4).unwrap())
+            .build()
+    }
+
+    #[test]
+    fn is_valid_rejects_color_overlap() {
+        let mut board = kings_only();
+        let d4 = Bitboard::from_single(&Loc::new(3, 3).unwrap());
+
+        board.white |= d4;
+        board.black |= d4;
+
+        assert!(board.is_valid(true).is_err());
+    }
+
+    #[test]
+    fn is_valid_rejects_kind_overlap() {
+        let mut board = kings_only();
+
+        board.queens |= Bitboard::from_single(&Loc::new(0, 4).unwrap());
+
+        assert!(board.is_valid(true).is_err());
+    }
+
+    #[test]
+    fn is_valid_rejects_union_mismatch() {
+        let mut board = kings_only();
+
+        board.white |= Bitboard::from_single(&Loc::new(3, 3).unwrap());
+
+        assert!(board.is_valid(true).is_err());
+    }
 }
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! In-place piece placement mutation, used by move application.
+
+use super::{Board, Piece, PieceKind};
+use crate::bitboard::Bitboard;
+use crate::loc::Loc;
+
+impl Board {
+    /// Removes whatever piece (if any) sits on `loc`.
+    pub(crate) fn remove_piece(&mut self, loc: &Loc) {
+        use PieceKind::*;
+
+        let clear = !Bitboard::from_single(loc);
+
+        for kind in [Pawn, Knight, Bishop, Rook, Queen, King] {
+            self[kind] &= clear;
+        }
+
+        self[true] &= clear;
+        self[false] &= clear;
+    }
+
+    /// Places `piece` on `loc`, without checking whether it is already occupied.
+    pub(crate) fn place_piece(&mut self, piece: &Piece, loc: &Loc) {
+        let set = Bitboard::from_single(loc);
+
+        self[piece.kind] |= set;
+        self[piece.white] |= set;
+    }
+}
@@ -9,10 +9,17 @@ pub type Result<T> = std::result::Result<T, BoardError>;
 pub enum BoardError {
     #[error("board structure corruption detected: {0}")]
     BoardCorruption(String),
+
+    #[error("invalid FEN: {0}")]
+    InvalidFen(String),
 }
 
 impl BoardError {
     pub fn board_corruption(msg: &str) -> Self {
         BoardError::BoardCorruption(msg.to_string())
     }
+
+    pub fn invalid_fen(msg: &str) -> Self {
+        BoardError::InvalidFen(msg.to_string())
+    }
 }
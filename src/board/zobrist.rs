@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Zobrist hashing of piece placement.
+
+use super::{Board, Piece, PieceKind};
+use crate::zobrist;
+
+impl Board {
+    /// Computes a Zobrist hash of `self`'s piece placement.
+    ///
+    /// This only covers piece placement; `GameState::zobrist` folds in the
+    /// side to move, castling rights, and en passant square on top of this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rookie::board::Board;
+    ///
+    /// let board = Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR").unwrap();
+    ///
+    /// assert_eq!(board.zobrist(), board.clone().zobrist());
+    /// ```
+    pub fn zobrist(&self) -> u64 {
+        use PieceKind::*;
+
+        let mut hash = 0u64;
+
+        for white in [true, false] {
+            for kind in [Pawn, Knight, Bishop, Rook, Queen, King] {
+                for loc in self.pieces(&Piece { kind, white }).iter() {
+                    hash ^= zobrist::piece_square_key(&Piece { kind, white }, &loc);
+                }
+            }
+        }
+
+        hash
+    }
+}
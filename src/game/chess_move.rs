@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+use crate::board::PieceKind;
+use crate::loc::Loc;
+
+/// Which side of the board a castling move happens on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastleSide {
+    King,
+    Queen,
+}
+
+/// A single chess move.
+///
+/// A `Move` only describes intent (source, destination, and any special
+/// handling); it carries no information about what piece is moving, since
+/// that is looked up from the `Board` it is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub from: Loc,
+    pub to: Loc,
+    pub promotion: Option<PieceKind>,
+    pub capture: bool,
+    pub en_passant: bool,
+    pub castle: Option<CastleSide>,
+}
+
+impl Move {
+    /// A non-capturing move.
+    pub fn quiet(from: Loc, to: Loc) -> Self {
+        Move { from, to, promotion: None, capture: false, en_passant: false, castle: None }
+    }
+
+    /// An ordinary capture.
+    pub fn capture(from: Loc, to: Loc) -> Self {
+        Move { from, to, promotion: None, capture: true, en_passant: false, castle: None }
+    }
+
+    /// An en passant capture.
+    pub fn en_passant(from: Loc, to: Loc) -> Self {
+        Move { from, to, promotion: None, capture: true, en_passant: true, castle: None }
+    }
+
+    /// A pawn promotion, optionally also a capture.
+    pub fn promotion(from: Loc, to: Loc, capture: bool, promotion: PieceKind) -> Self {
+        Move { from, to, promotion: Some(promotion), capture, en_passant: false, castle: None }
+    }
+
+    /// A castling move, described by the king's own source and destination.
+    pub fn castle(from: Loc, to: Loc, side: CastleSide) -> Self {
+        Move { from, to, promotion: None, capture: false, en_passant: false, castle: Some(side) }
+    }
+}
@@ -0,0 +1,393 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Move generation and application.
+//!
+//! `Board` only knows about piece placement; `GameState` adds the remaining
+//! information needed to play a game of chess (whose turn it is, castling
+//! rights, and the en passant square) and provides legal move generation and
+//! application on top of it.
+
+mod chess_move;
+mod result;
+
+pub use self::chess_move::{CastleSide, Move};
+pub use self::result::GameError;
+
+use crate::bitboard::{self, Bitboard};
+use crate::board::{Board, Piece, PieceKind};
+use crate::loc::Loc;
+use crate::magic;
+use crate::zobrist;
+use self::result::Result;
+
+/// Which castling moves a side still has the right to make.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CastlingRights {
+    pub white_king: bool,
+    pub white_queen: bool,
+    pub black_king: bool,
+    pub black_queen: bool,
+}
+
+impl CastlingRights {
+    /// Returns the rights remaining after `mv` is played, given that a rook
+    /// or king moving away from (or being captured on) its home square
+    /// permanently forfeits the corresponding right.
+    fn after_move(mut self, mv: &Move, white: bool, moved: PieceKind) -> Self {
+        if moved == PieceKind::King {
+            if white {
+                self.white_king = false;
+                self.white_queen = false;
+            } else {
+                self.black_king = false;
+                self.black_queen = false;
+            }
+        }
+
+        for loc in [mv.from, mv.to] {
+            match loc.rank_file() {
+                (0, 0) => self.white_queen = false,
+                (0, 7) => self.white_king = false,
+                (7, 0) => self.black_queen = false,
+                (7, 7) => self.black_king = false,
+                _ => {}
+            }
+        }
+
+        self
+    }
+}
+
+/// A complete chess position: piece placement plus the rest of the state
+/// needed to determine legal moves.
+#[derive(Debug, Clone)]
+pub struct GameState {
+    board: Board,
+    white_to_move: bool,
+    castling: CastlingRights,
+    en_passant: Option<Loc>,
+    hash: u64,
+}
+
+impl GameState {
+    /// Creates a new game state from its constituent parts.
+    pub fn new(
+        board: Board,
+        white_to_move: bool,
+        castling: CastlingRights,
+        en_passant: Option<Loc>,
+    ) -> Self {
+        let side = if white_to_move { zobrist::side_to_move_key() } else { 0 };
+        let hash = board.zobrist() ^ castling_hash(castling) ^ en_passant_hash(en_passant) ^ side;
+
+        GameState { board, white_to_move, castling, en_passant, hash }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn white_to_move(&self) -> bool {
+        self.white_to_move
+    }
+
+    pub fn castling(&self) -> CastlingRights {
+        self.castling
+    }
+
+    pub fn en_passant(&self) -> Option<Loc> {
+        self.en_passant
+    }
+
+    /// Returns the Zobrist hash of this position, covering piece placement,
+    /// side to move, castling rights, and the en passant square.
+    ///
+    /// `apply` maintains this incrementally (a handful of XORs per move)
+    /// rather than recomputing it from scratch, but the two always agree.
+    ///
+    /// # Examples
+    ///
+    /// The invariant holds across every kind of move, not just quiet ones:
+    /// a capture, a castle (which revokes both of the mover's rights at
+    /// once), a rook move away from its corner (which revokes only one
+    /// right), an en passant capture, and a promotion.
+    ///
+    /// ```
+    /// use rookie::board::Board;
+    /// use rookie::game::{CastleSide, CastlingRights, GameState};
+    /// use rookie::loc::Loc;
+    ///
+    /// fn assert_consistent(next: &GameState) {
+    ///     let recomputed = GameState::new(
+    ///         next.board().clone(),
+    ///         next.white_to_move(),
+    ///         next.castling(),
+    ///         next.en_passant(),
+    ///     );
+    ///
+    ///     assert_eq!(next.zobrist(), recomputed.zobrist());
+    /// }
+    ///
+    /// // Capture.
+    /// let board = Board::from_fen("8/8/8/8/4p3/3B4/8/8").unwrap();
+    /// let state = GameState::new(board, true, CastlingRights::default(), None);
+    /// let mv = state.legal_moves().into_iter().find(|m| m.capture).unwrap();
+    /// assert_consistent(&state.apply(&mv).unwrap());
+    ///
+    /// // Castling, which revokes both of the mover's rights at once.
+    /// let board = Board::from_fen("8/8/8/8/8/8/8/4K2R").unwrap();
+    /// let rights = CastlingRights { white_king: true, white_queen: true, ..CastlingRights::default() };
+    /// let state = GameState::new(board, true, rights, None);
+    /// let mv = state.legal_moves().into_iter().find(|m| m.castle == Some(CastleSide::King)).unwrap();
+    /// let next = state.apply(&mv).unwrap();
+    /// assert_consistent(&next);
+    /// assert!(!next.castling().white_king && !next.castling().white_queen);
+    ///
+    /// // A rook move away from its corner, which revokes only that side's right.
+    /// let board = Board::from_fen("8/8/8/8/8/8/8/4K2R").unwrap();
+    /// let state = GameState::new(board, true, rights, None);
+    /// let mv = state.legal_moves().into_iter().find(|m| m.from == Loc::new(0, 7).unwrap()).unwrap();
+    /// let next = state.apply(&mv).unwrap();
+    /// assert_consistent(&next);
+    /// assert!(!next.castling().white_king && next.castling().white_queen);
+    ///
+    /// // En passant capture.
+    /// let board = Board::from_fen("8/8/8/8/3pP3/8/8/8").unwrap();
+    /// let ep = Loc::new(2, 4).unwrap();
+    /// let state = GameState::new(board, false, CastlingRights::default(), Some(ep));
+    /// let mv = state.legal_moves().into_iter().find(|m| m.en_passant).unwrap();
+    /// assert_consistent(&state.apply(&mv).unwrap());
+    ///
+    /// // Promotion.
+    /// let board = Board::from_fen("8/4P3/8/8/8/8/8/8").unwrap();
+    /// let state = GameState::new(board, true, CastlingRights::default(), None);
+    /// let mv = state.legal_moves().into_iter().find(|m| m.promotion.is_some()).unwrap();
+    /// assert_consistent(&state.apply(&mv).unwrap());
+    /// ```
+    pub fn zobrist(&self) -> u64 {
+        self.hash
+    }
+
+    /// Generates every legal move available to the side to move.
+    ///
+    /// Pseudo-legal moves (which ignore whether they leave the mover's own
+    /// king in check) are generated first, then filtered by actually
+    /// applying each one to a cloned board.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let white = self.white_to_move;
+
+        self.pseudo_legal_moves()
+            .into_iter()
+            .filter(|mv| match self.apply(mv) {
+                Ok(next) => !next.board.in_check(white),
+                Err(_) => false,
+            })
+            .collect()
+    }
+
+    /// Applies `mv` to this position, returning the resulting state.
+    pub fn apply(&self, mv: &Move) -> Result<GameState> {
+        let white = self.white_to_move;
+        let mut board = self.board.clone();
+        let piece = board.at(&mv.from)?.ok_or(GameError::NoPieceAtSource)?;
+        let mut hash = self.hash ^ zobrist::piece_square_key(&piece, &mv.from);
+
+        if mv.en_passant {
+            let (from_rank, _) = mv.from.rank_file();
+            let (_, to_file) = mv.to.rank_file();
+            let captured_loc = Loc::new(from_rank, to_file).expect("en passant target is in bounds");
+            let captured = Piece { kind: PieceKind::Pawn, white: !white };
+
+            hash ^= zobrist::piece_square_key(&captured, &captured_loc);
+            board.remove_piece(&captured_loc);
+        } else if mv.capture {
+            let captured = board.at(&mv.to)?.ok_or(GameError::NoPieceAtSource)?;
+
+            hash ^= zobrist::piece_square_key(&captured, &mv.to);
+            board.remove_piece(&mv.to);
+        }
+
+        board.remove_piece(&mv.from);
+
+        let placed = Piece { kind: mv.promotion.unwrap_or(piece.kind), white };
+
+        board.place_piece(&placed, &mv.to);
+        hash ^= zobrist::piece_square_key(&placed, &mv.to);
+
+        if let Some(side) = mv.castle {
+            let rank = mv.from.rank_file().0;
+            let (rook_from, rook_to) = match side {
+                CastleSide::King => (
+                    Loc::new(rank, 7).expect("rank is in bounds"),
+                    Loc::new(rank, 5).expect("rank is in bounds"),
+                ),
+                CastleSide::Queen => (
+                    Loc::new(rank, 0).expect("rank is in bounds"),
+                    Loc::new(rank, 3).expect("rank is in bounds"),
+                ),
+            };
+            let rook = board.at(&rook_from)?.ok_or(GameError::NoPieceAtSource)?;
+
+            board.remove_piece(&rook_from);
+            board.place_piece(&rook, &rook_to);
+            hash ^= zobrist::piece_square_key(&rook, &rook_from) ^ zobrist::piece_square_key(&rook, &rook_to);
+        }
+
+        let (from_rank, from_file) = mv.from.rank_file();
+        let (to_rank, _) = mv.to.rank_file();
+        let en_passant = (piece.kind == PieceKind::Pawn && from_rank.abs_diff(to_rank) == 2)
+            .then(|| Loc::new((from_rank + to_rank) / 2, from_file).expect("midpoint is in bounds"));
+
+        let castling = self.castling.after_move(mv, white, piece.kind);
+
+        hash ^= castling_hash(self.castling) ^ castling_hash(castling);
+        hash ^= en_passant_hash(self.en_passant) ^ en_passant_hash(en_passant);
+        hash ^= zobrist::side_to_move_key();
+
+        Ok(GameState { board, white_to_move: !white, castling, en_passant, hash })
+    }
+
+    fn pseudo_legal_moves(&self) -> Vec<Move> {
+        let occ = self.board[true] | self.board[false];
+        let mut moves = self.pawn_moves();
+
+        moves.extend(self.leaper_or_slider_moves(PieceKind::Knight, bitboard::knight_attacks));
+        moves.extend(self.leaper_or_slider_moves(PieceKind::King, bitboard::king_attacks));
+        moves.extend(self.leaper_or_slider_moves(PieceKind::Rook, |loc| magic::rook_attacks(loc, occ)));
+        moves.extend(self.leaper_or_slider_moves(PieceKind::Bishop, |loc| magic::bishop_attacks(loc, occ)));
+        moves.extend(self.leaper_or_slider_moves(PieceKind::Queen, |loc| magic::queen_attacks(loc, occ)));
+        moves.extend(self.castling_moves());
+
+        moves
+    }
+
+    /// Generates moves for a piece kind whose attack set for a given square
+    /// is fully described by `attacks` (already accounting for blockers, in
+    /// the sliding case).
+    fn leaper_or_slider_moves(&self, kind: PieceKind, attacks: impl Fn(&Loc) -> Bitboard) -> Vec<Move> {
+        let white = self.white_to_move;
+        let own = self.board[white];
+        let enemy = self.board[!white];
+        let mut moves = Vec::new();
+
+        for from in self.board.pieces(&Piece { kind, white }).iter() {
+            for to in (attacks(&from) - own).iter() {
+                if enemy.at(&to) {
+                    moves.push(Move::capture(from, to));
+                } else {
+                    moves.push(Move::quiet(from, to));
+                }
+            }
+        }
+
+        moves
+    }
+
+    // The nested `if`s below each depend on state established by the one
+    // enclosing them (the push-one-square destination, then the start-rank
+    // check, then the push-two-squares destination), so collapsing them with
+    // `&&` would need let-chains, which aren't available without pinning the
+    // crate to the 2024 edition.
+    #[allow(clippy::collapsible_if)]
+    fn pawn_moves(&self) -> Vec<Move> {
+        let white = self.white_to_move;
+        let occ = self.board[true] | self.board[false];
+        let enemy = self.board[!white];
+        let (forward, start_rank, promotion_rank) =
+            if white { (bitboard::Direction::North, 1, 7) } else { (bitboard::Direction::South, 6, 0) };
+        let mut moves = Vec::new();
+
+        for from in self.board.pieces(&Piece { kind: PieceKind::Pawn, white }).iter() {
+            let single = Bitboard::from_single(&from).shift(forward);
+
+            if let Ok(to) = single.try_into_loc() {
+                if !occ.at(&to) {
+                    push_pawn_advance(&mut moves, from, to, false, promotion_rank);
+
+                    if from.rank_file().0 == start_rank {
+                        if let Ok(to) = single.shift(forward).try_into_loc() {
+                            if !occ.at(&to) {
+                                moves.push(Move::quiet(from, to));
+                            }
+                        }
+                    }
+                }
+            }
+
+            for to in (bitboard::pawn_attacks(&from, white) & enemy).iter() {
+                push_pawn_advance(&mut moves, from, to, true, promotion_rank);
+            }
+
+            if let Some(ep) = self.en_passant {
+                if bitboard::pawn_attacks(&from, white).at(&ep) {
+                    moves.push(Move::en_passant(from, ep));
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn castling_moves(&self) -> Vec<Move> {
+        let white = self.white_to_move;
+        let mut moves = Vec::new();
+
+        if self.board.in_check(white) {
+            return moves;
+        }
+
+        let occ = self.board[true] | self.board[false];
+        let rank = if white { 0 } else { 7 };
+        let king_from = Loc::new(rank, 4).expect("rank is in bounds");
+        let (king_side, queen_side) =
+            if white { (self.castling.white_king, self.castling.white_queen) } else { (self.castling.black_king, self.castling.black_queen) };
+
+        if king_side {
+            let f = Loc::new(rank, 5).expect("rank is in bounds");
+            let g = Loc::new(rank, 6).expect("rank is in bounds");
+
+            if !occ.at(&f) && !occ.at(&g)
+                && !self.board.square_attacked_by(&f, !white)
+                && !self.board.square_attacked_by(&g, !white)
+            {
+                moves.push(Move::castle(king_from, g, CastleSide::King));
+            }
+        }
+
+        if queen_side {
+            let b = Loc::new(rank, 1).expect("rank is in bounds");
+            let c = Loc::new(rank, 2).expect("rank is in bounds");
+            let d = Loc::new(rank, 3).expect("rank is in bounds");
+
+            if !occ.at(&b) && !occ.at(&c) && !occ.at(&d)
+                && !self.board.square_attacked_by(&c, !white)
+                && !self.board.square_attacked_by(&d, !white)
+            {
+                moves.push(Move::castle(king_from, c, CastleSide::Queen));
+            }
+        }
+
+        moves
+    }
+}
+
+fn castling_hash(rights: CastlingRights) -> u64 {
+    zobrist::castling_key(rights.white_king, rights.white_queen, rights.black_king, rights.black_queen)
+}
+
+fn en_passant_hash(en_passant: Option<Loc>) -> u64 {
+    en_passant.map(|loc| zobrist::en_passant_key(loc.rank_file().1)).unwrap_or(0)
+}
+
+fn push_pawn_advance(moves: &mut Vec<Move>, from: Loc, to: Loc, capture: bool, promotion_rank: u8) {
+    if to.rank_file().0 == promotion_rank {
+        for kind in [PieceKind::Queen, PieceKind::Rook, PieceKind::Bishop, PieceKind::Knight] {
+            moves.push(Move::promotion(from, to, capture, kind));
+        }
+    } else if capture {
+        moves.push(Move::capture(from, to));
+    } else {
+        moves.push(Move::quiet(from, to));
+    }
+}
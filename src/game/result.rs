@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+use thiserror::Error;
+
+use crate::board::BoardError;
+
+pub type Result<T> = std::result::Result<T, GameError>;
+
+#[derive(Error, Debug)]
+pub enum GameError {
+    /// Returned when the move's source square, its capture target, or the
+    /// castling rook's home square is empty.
+    #[error("no piece stands on a square the move needs a piece on")]
+    NoPieceAtSource,
+
+    #[error(transparent)]
+    Board(#[from] BoardError),
+}
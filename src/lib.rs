@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! `rookie` is a bitboard-based chess board representation and move generator.
+
+pub mod bitboard;
+pub mod board;
+pub mod game;
+pub mod loc;
+pub mod magic;
+pub mod zobrist;
+
+mod rng;
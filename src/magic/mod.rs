@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Magic-bitboard attack generation for sliding pieces.
+//!
+//! Rooks, bishops, and queens can attack a number of squares that depends on
+//! where other pieces (blockers) sit along their rays, which rules out a
+//! simple precomputed table keyed only by square. Magic bitboards solve this
+//! by hashing the *relevant* occupancy (the blocker squares that can actually
+//! change the attack set) down to a small, collision-free index with a
+//! single multiplication, so lookup stays O(1).
+//!
+//! The magic constants are not known in closed form; they are found by
+//! random trial offline, in `build.rs`, mirroring the table generation the
+//! seer engine performs in its own build script. This module only consumes
+//! the search's output (`ROOK_MAGICS`/`BISHOP_MAGICS`, generated into
+//! `OUT_DIR/magics.rs`) to rebuild the actual attack tables.
+
+use std::sync::OnceLock;
+
+use crate::bitboard::Bitboard;
+use crate::loc::Loc;
+
+include!(concat!(env!("OUT_DIR"), "/magics.rs"));
+
+const ROOK_DIRS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// A precomputed magic entry for a single square.
+struct MagicEntry {
+    mask: Bitboard,
+    magic: u64,
+    shift: u32,
+    table: Vec<Bitboard>,
+}
+
+impl MagicEntry {
+    fn attacks(&self, occ: Bitboard) -> Bitboard {
+        let relevant = occ & self.mask;
+        let index = (relevant.raw().wrapping_mul(self.magic) >> self.shift) as usize;
+
+        self.table[index]
+    }
+}
+
+/// Walks every ray in `dirs` from `sq`, stopping (inclusive) at the first
+/// blocker set in `occ`, and returns the resulting attack set.
+fn ray_attacks(sq: u8, occ: Bitboard, dirs: [(i8, i8); 4]) -> Bitboard {
+    let rank = (sq / 8) as i8;
+    let file = (sq % 8) as i8;
+    let mut attacked = Vec::new();
+
+    for (dr, df) in dirs {
+        let mut r = rank + dr;
+        let mut f = file + df;
+
+        while (0..8).contains(&r) && (0..8).contains(&f) {
+            let loc = Loc::new(r as u8, f as u8).expect("in-bounds rank/file");
+            attacked.push(loc);
+
+            if occ.at(&loc) {
+                break;
+            }
+
+            r += dr;
+            f += df;
+        }
+    }
+
+    Bitboard::new(&attacked)
+}
+
+/// Computes the relevant-occupancy mask for `sq`: every square along the
+/// rays in `dirs`, excluding the last square of each ray (the board edge
+/// reached in that direction), since a blocker there never changes whether
+/// it can be moved onto.
+fn relevant_mask(sq: u8, dirs: [(i8, i8); 4]) -> Bitboard {
+    let rank = (sq / 8) as i8;
+    let file = (sq % 8) as i8;
+    let mut relevant = Vec::new();
+
+    for (dr, df) in dirs {
+        let mut r = rank + dr;
+        let mut f = file + df;
+
+        while (0..8).contains(&r) && (0..8).contains(&f) && (0..8).contains(&(r + dr)) && (0..8).contains(&(f + df)) {
+            relevant.push(Loc::new(r as u8, f as u8).expect("in-bounds rank/file"));
+            r += dr;
+            f += df;
+        }
+    }
+
+    Bitboard::new(&relevant)
+}
+
+/// Enumerates every blocker subset of `mask` via the carry-rippler trick.
+fn subsets(mask: Bitboard) -> Vec<Bitboard> {
+    let raw = mask.raw();
+    let mut subsets = Vec::with_capacity(1 << raw.count_ones());
+    let mut sub = 0u64;
+
+    loop {
+        subsets.push(Bitboard::from_u64(sub));
+        sub = sub.wrapping_sub(raw) & raw;
+
+        if sub == 0 {
+            break;
+        }
+    }
+
+    subsets
+}
+
+/// Builds the magic entry for `sq` from its precomputed `magic` number (see
+/// `build.rs`), filling every subset's table slot directly instead of
+/// searching: the number is already known to map subsets that share a slot
+/// only to identical attack sets.
+fn build_entry(sq: u8, magic: u64, dirs: [(i8, i8); 4]) -> MagicEntry {
+    let mask = relevant_mask(sq, dirs);
+    let bits = mask.raw().count_ones();
+    let shift = 64 - bits;
+    let mut table = vec![Bitboard::default(); 1 << bits];
+
+    for occ in subsets(mask) {
+        let index = (occ.raw().wrapping_mul(magic) >> shift) as usize;
+
+        table[index] = ray_attacks(sq, occ, dirs);
+    }
+
+    MagicEntry { mask, magic, shift, table }
+}
+
+fn build_tables(dirs: [(i8, i8); 4], magics: &[u64; 64]) -> Vec<MagicEntry> {
+    (0..64u8).map(|sq| build_entry(sq, magics[sq as usize], dirs)).collect()
+}
+
+static ROOK_ATTACK_TABLES: OnceLock<Vec<MagicEntry>> = OnceLock::new();
+static BISHOP_ATTACK_TABLES: OnceLock<Vec<MagicEntry>> = OnceLock::new();
+
+/// Returns the squares a rook attacks, given a board occupancy.
+///
+/// # Arguments
+///
+/// * `loc`: Square the rook stands on.
+/// * `occ`: Occupancy of the whole board (both colors, any piece kind).
+///
+/// # Returns
+///
+/// A `Bitboard` of every square the rook attacks, including the first
+/// blocker hit in each direction (it may be a capture).
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::Bitboard;
+/// use rookie::loc::Loc;
+/// use rookie::magic::rook_attacks;
+///
+/// let d4 = Loc::new(3, 3).unwrap();
+/// let occ = Bitboard::new(&[
+///     Loc::new(6, 3).unwrap(), // d7, blocks the north ray
+///     Loc::new(1, 3).unwrap(), // d2, blocks the south ray
+///     Loc::new(3, 6).unwrap(), // g4, blocks the east ray
+///     Loc::new(3, 1).unwrap(), // b4, blocks the west ray
+/// ]);
+///
+/// let attacks = rook_attacks(&d4, occ);
+///
+/// assert_eq!(attacks.count(), 10);
+/// assert!(attacks.at(&Loc::new(6, 3).unwrap())); // d7 itself is attacked
+/// assert!(!attacks.at(&Loc::new(7, 3).unwrap())); // d8 is beyond the blocker
+/// assert!(attacks.at(&Loc::new(3, 1).unwrap())); // b4 itself is attacked
+/// assert!(!attacks.at(&Loc::new(3, 0).unwrap())); // a4 is beyond the blocker
+/// ```
+pub fn rook_attacks(loc: &Loc, occ: Bitboard) -> Bitboard {
+    let tables = ROOK_ATTACK_TABLES.get_or_init(|| build_tables(ROOK_DIRS, &ROOK_MAGICS));
+
+    tables[loc.index() as usize].attacks(occ)
+}
+
+/// Returns the squares a bishop attacks, given a board occupancy.
+///
+/// See [`rook_attacks`] for the arguments and semantics; this is the
+/// diagonal-rays equivalent.
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::Bitboard;
+/// use rookie::loc::Loc;
+/// use rookie::magic::bishop_attacks;
+///
+/// let d4 = Loc::new(3, 3).unwrap();
+/// let occ = Bitboard::new(&[
+///     Loc::new(5, 5).unwrap(), // f6, blocks the north-east ray
+///     Loc::new(5, 1).unwrap(), // b6, blocks the north-west ray
+///     Loc::new(1, 5).unwrap(), // f2, blocks the south-east ray
+///     Loc::new(1, 1).unwrap(), // b2, blocks the south-west ray
+/// ]);
+///
+/// let attacks = bishop_attacks(&d4, occ);
+///
+/// assert_eq!(attacks.count(), 8);
+/// assert!(attacks.at(&Loc::new(5, 5).unwrap())); // f6 itself is attacked
+/// assert!(!attacks.at(&Loc::new(6, 6).unwrap())); // g7 is beyond the blocker
+/// ```
+pub fn bishop_attacks(loc: &Loc, occ: Bitboard) -> Bitboard {
+    let tables = BISHOP_ATTACK_TABLES.get_or_init(|| build_tables(BISHOP_DIRS, &BISHOP_MAGICS));
+
+    tables[loc.index() as usize].attacks(occ)
+}
+
+/// Returns the squares a queen attacks, given a board occupancy.
+///
+/// A queen moves as a rook and a bishop combined, so this is simply the
+/// union of [`rook_attacks`] and [`bishop_attacks`].
+///
+/// # Examples
+///
+/// ```
+/// use rookie::bitboard::EMPTY;
+/// use rookie::loc::Loc;
+/// use rookie::magic::queen_attacks;
+///
+/// // On an empty board, a cornered queen sees its rank, its file, and the
+/// // one diagonal running through the corner: 7 + 7 + 7 = 21 squares.
+/// let attacks = queen_attacks(&Loc::new(0, 0).unwrap(), EMPTY);
+///
+/// assert_eq!(attacks.count(), 21);
+/// assert!(attacks.at(&Loc::new(0, 4).unwrap())); // e1, along the rank
+/// assert!(attacks.at(&Loc::new(4, 0).unwrap())); // a5, along the file
+/// assert!(attacks.at(&Loc::new(4, 4).unwrap())); // e5, along the diagonal
+/// ```
+pub fn queen_attacks(loc: &Loc, occ: Bitboard) -> Bitboard {
+    rook_attacks(loc, occ) | bishop_attacks(loc, occ)
+}
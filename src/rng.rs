@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! A small deterministic pseudo-random number generator.
+//!
+//! This crate needs reproducible randomness for generating Zobrist key
+//! tables without pulling in an extra dependency. [`Rng`] is a splitmix64
+//! generator: fast, good enough distribution for our purposes, and trivially
+//! seedable so results are stable across runs and platforms. `build.rs`
+//! duplicates this generator for its own offline magic-number search, since
+//! build scripts cannot depend on the crate they build.
+
+/// A splitmix64 pseudo-random number generator.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Creates a new generator from a fixed seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Produces the next pseudo-random 64-bit value.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: GPL-3.0-only
+// SPDX-FileCopyrightText: Duszku
+
+//! Zobrist hashing of chess positions.
+//!
+//! A Zobrist hash XORs together one random key per "fact" true of a
+//! position (a piece standing on a square, the current castling rights,
+//! the en passant file, whose turn it is), so that toggling any single
+//! fact is a single XOR rather than a full rehash. This makes the hash
+//! suitable both as a transposition-table key and for cheap incremental
+//! maintenance across `GameState::apply`.
+//!
+//! The keys are generated once, lazily, from a fixed seed, mirroring how
+//! the `magic` module lazily builds its attack tables.
+
+use std::sync::OnceLock;
+
+use crate::board::{Piece, PieceKind};
+use crate::loc::Loc;
+use crate::rng::Rng;
+
+/// Seed used for key generation, kept fixed so hashes (and thus the keys
+/// squares map to) are reproducible across runs.
+const SEED: u64 = 0x005A_6F62_7269_7374;
+
+struct ZobristKeys {
+    piece_square: [[[u64; 64]; 6]; 2],
+    castling: [u64; 16],
+    en_passant_file: [u64; 8],
+    side_to_move: u64,
+}
+
+fn build_keys() -> ZobristKeys {
+    let mut rng = Rng::new(SEED);
+    let mut piece_square = [[[0u64; 64]; 6]; 2];
+
+    for color in &mut piece_square {
+        for kind in color.iter_mut() {
+            for key in kind.iter_mut() {
+                *key = rng.next_u64();
+            }
+        }
+    }
+
+    let mut castling = [0u64; 16];
+
+    for key in &mut castling {
+        *key = rng.next_u64();
+    }
+
+    let mut en_passant_file = [0u64; 8];
+
+    for key in &mut en_passant_file {
+        *key = rng.next_u64();
+    }
+
+    ZobristKeys { piece_square, castling, en_passant_file, side_to_move: rng.next_u64() }
+}
+
+static KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+
+fn keys() -> &'static ZobristKeys {
+    KEYS.get_or_init(build_keys)
+}
+
+fn kind_index(kind: PieceKind) -> usize {
+    use PieceKind::*;
+
+    match kind {
+        Pawn => 0,
+        Knight => 1,
+        Bishop => 2,
+        Rook => 3,
+        Queen => 4,
+        King => 5,
+    }
+}
+
+/// Returns the key for `piece` standing on `loc`.
+pub fn piece_square_key(piece: &Piece, loc: &Loc) -> u64 {
+    keys().piece_square[piece.white as usize][kind_index(piece.kind)][loc.index() as usize]
+}
+
+/// Returns the key for a combination of castling rights.
+pub fn castling_key(white_king: bool, white_queen: bool, black_king: bool, black_queen: bool) -> u64 {
+    let index = white_king as usize
+        | (white_queen as usize) << 1
+        | (black_king as usize) << 2
+        | (black_queen as usize) << 3;
+
+    keys().castling[index]
+}
+
+/// Returns the key for an en passant target square standing on `file`.
+pub fn en_passant_key(file: u8) -> u64 {
+    keys().en_passant_file[file as usize]
+}
+
+/// Returns the key toggled whenever the side to move changes.
+pub fn side_to_move_key() -> u64 {
+    keys().side_to_move
+}